@@ -20,6 +20,18 @@ impl Command for SubCommand {
                 SyntaxShape::CellPath,
                 "column paths to convert to boolean (for table input)",
             )
+            .named(
+                "true-values",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "additional strings to recognize as true",
+                None,
+            )
+            .named(
+                "false-values",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "additional strings to recognize as false",
+                None,
+            )
             .category(Category::Conversions)
     }
 
@@ -94,10 +106,23 @@ impl Command for SubCommand {
                 example: "'true' | into bool",
                 result: Some(Value::boolean(true, span)),
             },
+            Example {
+                description: "convert a yes/no style string to boolean",
+                example: "'no' | into bool",
+                result: Some(Value::boolean(false, span)),
+            },
+            Example {
+                description: "convert a domain-specific string to boolean",
+                example: "'Y' | into bool --true-values [Y] --false-values [N]",
+                result: Some(Value::boolean(true, span)),
+            },
         ]
     }
 }
 
+const TRUE_STRINGS: &[&str] = &["true", "yes", "y", "on", "t"];
+const FALSE_STRINGS: &[&str] = &["false", "no", "n", "off", "f", ""];
+
 fn into_bool(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -106,16 +131,26 @@ fn into_bool(
 ) -> Result<PipelineData, ShellError> {
     let head = call.head;
     let column_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+    let true_values: Vec<String> = call
+        .get_flag(engine_state, stack, "true-values")?
+        .unwrap_or_default();
+    let false_values: Vec<String> = call
+        .get_flag(engine_state, stack, "false-values")?
+        .unwrap_or_default();
 
     input.map(
         move |v| {
             if column_paths.is_empty() {
-                action(&v, head)
+                action(&v, head, &true_values, &false_values)
             } else {
                 let mut ret = v;
                 for path in &column_paths {
-                    let r =
-                        ret.update_cell_path(&path.members, Box::new(move |old| action(old, head)));
+                    let true_values = true_values.clone();
+                    let false_values = false_values.clone();
+                    let r = ret.update_cell_path(
+                        &path.members,
+                        Box::new(move |old| action(old, head, &true_values, &false_values)),
+                    );
                     if let Err(error) = r {
                         return Value::Error { error };
                     }
@@ -128,25 +163,40 @@ fn into_bool(
     )
 }
 
-fn string_to_boolean(s: &str, span: Span) -> Result<bool, ShellError> {
-    match s.trim().to_lowercase().as_str() {
-        "true" => Ok(true),
-        "false" => Ok(false),
-        o => {
-            let val = o.parse::<f64>();
-            match val {
-                Ok(f) => Ok(f.abs() >= f64::EPSILON),
-                Err(_) => Err(ShellError::CantConvert(
-                    "boolean".to_string(),
-                    "string".to_string(),
-                    span,
-                )),
-            }
-        }
+fn string_to_boolean(
+    s: &str,
+    span: Span,
+    true_values: &[String],
+    false_values: &[String],
+) -> Result<bool, ShellError> {
+    let trimmed = s.trim();
+
+    if true_values.iter().any(|v| v.eq_ignore_ascii_case(trimmed)) {
+        return Ok(true);
+    }
+    if false_values.iter().any(|v| v.eq_ignore_ascii_case(trimmed)) {
+        return Ok(false);
+    }
+
+    let lower = trimmed.to_lowercase();
+    if TRUE_STRINGS.contains(&lower.as_str()) {
+        return Ok(true);
+    }
+    if FALSE_STRINGS.contains(&lower.as_str()) {
+        return Ok(false);
+    }
+
+    match lower.parse::<f64>() {
+        Ok(f) => Ok(f.abs() >= f64::EPSILON),
+        Err(_) => Err(ShellError::CantConvert(
+            "boolean".to_string(),
+            "string".to_string(),
+            span,
+        )),
     }
 }
 
-fn action(input: &Value, span: Span) -> Value {
+fn action(input: &Value, span: Span, true_values: &[String], false_values: &[String]) -> Value {
     match input {
         Value::Bool { .. } => input.clone(),
         Value::Int { val, .. } => Value::Bool {
@@ -157,13 +207,18 @@ fn action(input: &Value, span: Span) -> Value {
             val: val.abs() >= f64::EPSILON,
             span,
         },
-        Value::String { val, .. } => match string_to_boolean(val, span) {
-            Ok(val) => Value::Bool { val, span },
-            Err(error) => Value::Error { error },
-        },
-        _ => Value::Error {
+        Value::String { val, .. } => {
+            match string_to_boolean(val, span, true_values, false_values) {
+                Ok(val) => Value::Bool { val, span },
+                Err(error) => Value::Error { error },
+            }
+        }
+        other => Value::Error {
             error: ShellError::UnsupportedInput(
-                "'into bool' does not support this input".into(),
+                format!(
+                    "'into bool' does not support {} input; expected one of: bool, int, float, string",
+                    other.get_type()
+                ),
                 span,
             ),
         },
@@ -6,6 +6,7 @@ use nu_protocol::{
     Category, Example, FromValue, IntoInterruptiblePipelineData, PipelineData, PipelineIterator,
     Range, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
 };
+use std::collections::VecDeque;
 
 #[derive(Clone)]
 pub struct DropNth;
@@ -73,6 +74,43 @@ impl Command for DropNth {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Drop rows from the third to the end",
+                example: "echo [0,1,2,3,4,5] | drop nth (2..)",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(0), Value::test_int(1)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Drop the last two rows, counting from the end",
+                example: "echo [first second third fourth fifth] | drop nth (-2..)",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("first"),
+                        Value::test_string("second"),
+                        Value::test_string("third"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Drop every other row",
+                example: "echo [0,1,2,3,4,5] | drop nth (0..2..5)",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(3), Value::test_int(5)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description:
+                    "Drop from the third row through the end, using a negative upper bound",
+                example: "echo [0,1,2,3,4,5] | drop nth (2..-1)",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(0), Value::test_int(1)],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 
@@ -83,38 +121,26 @@ impl Command for DropNth {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        // let mut rows: Vec<usize> = call.rest(engine_state, stack, 0)?;
-        // rows.sort_unstable();
-        // let pipeline_iter: PipelineIterator = input.into_iter();
-
         let number_or_range = extract_int_or_range(engine_state, stack, call)?;
-        let rows = match number_or_range {
+
+        let iter = match number_or_range {
             Either::Left(row_number) => {
                 let and_rows: Vec<Spanned<i64>> = call.rest(engine_state, stack, 1)?;
 
                 let mut rows: Vec<_> = and_rows.into_iter().map(|x| x.item as usize).collect();
                 rows.push(row_number as usize);
                 rows.sort_unstable();
-                rows
-            }
-            Either::Right(row_range) => {
-                let from = row_range.from.as_integer()? as usize;
-                let to = row_range.to.as_integer()? as usize;
 
-                if matches!(row_range.inclusion, RangeInclusion::Inclusive) {
-                    (from..=to).collect()
-                } else {
-                    (from..to).collect()
+                DropNthIterator::Indices {
+                    input: input.into_iter(),
+                    rows,
+                    current: 0,
                 }
             }
+            Either::Right(row_range) => range_drop_iterator(row_range, input)?,
         };
 
-        Ok(DropNthIterator {
-            input: input.into_iter(),
-            rows,
-            current: 0,
-        }
-        .into_pipeline_data(engine_state.ctrlc.clone()))
+        Ok(iter.into_pipeline_data(engine_state.ctrlc.clone()))
     }
 }
 
@@ -138,30 +164,247 @@ fn extract_int_or_range(
     })
 }
 
-struct DropNthIterator {
-    input: PipelineIterator,
-    rows: Vec<usize>,
-    current: usize,
+/// A range bound is either a concrete index, a negative index counting back from the
+/// end, or absent (open-ended), which `FromValue` represents as `Value::Nothing`.
+fn bound_as_opt_i64(value: &Value) -> Result<Option<i64>, ShellError> {
+    match value {
+        Value::Nothing { .. } => Ok(None),
+        other => other.as_integer().map(Some),
+    }
+}
+
+fn range_drop_iterator(
+    row_range: Range,
+    input: PipelineData,
+) -> Result<DropNthIterator, ShellError> {
+    let inclusive = matches!(row_range.inclusion, RangeInclusion::Inclusive);
+    let step = bound_as_opt_i64(&row_range.incr)?
+        .unwrap_or(1)
+        .unsigned_abs()
+        .max(1) as usize;
+    let from = bound_as_opt_i64(&row_range.from)?.ok_or_else(|| {
+        ShellError::TypeMismatch(
+            "a starting value for the range".into(),
+            row_range.from.span().unwrap_or_else(|_| Span::new(0, 0)),
+        )
+    })?;
+    let to = bound_as_opt_i64(&row_range.to)?;
+
+    let iter = match (from, to) {
+        (from, Some(to)) if from >= 0 && to >= 0 => {
+            // Finite, non-negative range: the original sorted-index fast path, now
+            // stepped.
+            let rows: Vec<usize> = if inclusive {
+                (from as usize..=to as usize).step_by(step).collect()
+            } else {
+                (from as usize..to as usize).step_by(step).collect()
+            };
+
+            DropNthIterator::Indices {
+                input: input.into_iter(),
+                rows,
+                current: 0,
+            }
+        }
+        (from, None) if from >= 0 => {
+            // Open-ended but anchored at the start: streams freely, no buffering needed.
+            DropNthIterator::OpenEnded {
+                input: input.into_iter(),
+                start: from as usize,
+                step,
+                current: 0,
+            }
+        }
+        (from, Some(to)) if from >= 0 && to < 0 => {
+            // Fixed start, end-relative upper bound: the real upper bound can't be
+            // known until EOF, so buffer everything from `from` onward rather than
+            // a suffix window (there's no fixed-size window that would cover it).
+            DropNthIterator::Buffered {
+                input: input.into_iter(),
+                from,
+                to: Some(to),
+                step,
+                inclusive,
+                policy: BufferPolicy::FromIndex(from as usize),
+                buffer: VecDeque::new(),
+                output: VecDeque::new(),
+                current: 0,
+                exhausted: false,
+            }
+        }
+        (from, to) => {
+            // Both bounds (or just `from`) count from the end: only the last
+            // max(|from|, |to|) rows can possibly be affected, so that's all that
+            // needs to be buffered.
+            let window = match to {
+                Some(to) if to < 0 => from.unsigned_abs().max(to.unsigned_abs()) as usize,
+                _ => from.unsigned_abs() as usize,
+            };
+
+            DropNthIterator::Buffered {
+                input: input.into_iter(),
+                from,
+                to,
+                step,
+                inclusive,
+                policy: BufferPolicy::SuffixWindow(window),
+                buffer: VecDeque::with_capacity(window),
+                output: VecDeque::new(),
+                current: 0,
+                exhausted: false,
+            }
+        }
+    };
+
+    Ok(iter)
+}
+
+enum BufferPolicy {
+    /// Keep only the last `n` seen elements, evicting (and passing through) older ones
+    /// as new ones arrive. Used when the start of the range counts from the end, so
+    /// the affected suffix has a known fixed size.
+    SuffixWindow(usize),
+    /// Pass through every element before absolute index `n` unchanged, and buffer
+    /// everything from `n` onward until EOF. Used when the start is a fixed index but
+    /// the end is end-relative, so the buffered region can't be bounded in advance.
+    FromIndex(usize),
+}
+
+enum DropNthIterator {
+    Indices {
+        input: PipelineIterator,
+        rows: Vec<usize>,
+        current: usize,
+    },
+    OpenEnded {
+        input: PipelineIterator,
+        start: usize,
+        step: usize,
+        current: usize,
+    },
+    Buffered {
+        input: PipelineIterator,
+        from: i64,
+        to: Option<i64>,
+        step: usize,
+        inclusive: bool,
+        policy: BufferPolicy,
+        buffer: VecDeque<(usize, Value)>,
+        output: VecDeque<Value>,
+        current: usize,
+        exhausted: bool,
+    },
 }
 
 impl Iterator for DropNthIterator {
     type Item = Value;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if let Some(row) = self.rows.get(0) {
-                if self.current == *row {
-                    self.rows.remove(0);
-                    self.current += 1;
-                    let _ = self.input.next();
-                    continue;
+        match self {
+            DropNthIterator::Indices {
+                input,
+                rows,
+                current,
+            } => loop {
+                if let Some(row) = rows.get(0) {
+                    if *current == *row {
+                        rows.remove(0);
+                        *current += 1;
+                        let _ = input.next();
+                        continue;
+                    } else {
+                        *current += 1;
+                        return input.next();
+                    }
                 } else {
-                    self.current += 1;
-                    return self.input.next();
+                    return input.next();
                 }
-            } else {
-                return self.input.next();
-            }
+            },
+            DropNthIterator::OpenEnded {
+                input,
+                start,
+                step,
+                current,
+            } => loop {
+                let value = input.next()?;
+                let idx = *current;
+                *current += 1;
+
+                if idx >= *start && (idx - *start) % *step == 0 {
+                    continue;
+                }
+
+                return Some(value);
+            },
+            DropNthIterator::Buffered {
+                input,
+                from,
+                to,
+                step,
+                inclusive,
+                policy,
+                buffer,
+                output,
+                current,
+                exhausted,
+            } => loop {
+                if let Some(value) = output.pop_front() {
+                    return Some(value);
+                }
+
+                if *exhausted {
+                    return None;
+                }
+
+                match input.next() {
+                    Some(value) => {
+                        let idx = *current;
+                        *current += 1;
+
+                        match *policy {
+                            BufferPolicy::SuffixWindow(window) => {
+                                buffer.push_back((idx, value));
+
+                                if buffer.len() > window {
+                                    let (_, old_value) =
+                                        buffer.pop_front().expect("just checked len");
+                                    return Some(old_value);
+                                }
+                            }
+                            BufferPolicy::FromIndex(start) => {
+                                if idx < start {
+                                    return Some(value);
+                                }
+                                buffer.push_back((idx, value));
+                            }
+                        }
+                    }
+                    None => {
+                        *exhausted = true;
+                        let total = *current as i64;
+                        let real_from = if *from < 0 { total + *from } else { *from };
+                        let real_to = match *to {
+                            Some(t) if t < 0 => total + t,
+                            Some(t) => t,
+                            None => total - 1,
+                        };
+
+                        for (idx, value) in buffer.drain(..) {
+                            let idx = idx as i64;
+                            let in_range = if *inclusive {
+                                idx >= real_from && idx <= real_to
+                            } else {
+                                idx >= real_from && idx < real_to
+                            };
+                            let on_step = (idx - real_from).rem_euclid(*step as i64) == 0;
+
+                            if !(in_range && on_step) {
+                                output.push_back(value);
+                            }
+                        }
+                    }
+                }
+            },
         }
     }
 }
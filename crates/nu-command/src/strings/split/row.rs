@@ -4,6 +4,7 @@ use nu_protocol::{
     engine::{Command, EngineState, Stack},
     Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
 };
+use regex::Regex;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -20,6 +21,22 @@ impl Command for SubCommand {
                 SyntaxShape::String,
                 "the character that denotes what separates rows",
             )
+            .named(
+                "number",
+                SyntaxShape::Int,
+                "the max number of rows to split into",
+                Some('n'),
+            )
+            .switch(
+                "regex",
+                "separator is a regular expression, not a literal string",
+                Some('r'),
+            )
+            .switch(
+                "drop-empty",
+                "drop empty or whitespace-only segments from the result, instead of keeping them (changes row count)",
+                None,
+            )
             .category(Category::Strings)
     }
 
@@ -63,6 +80,55 @@ impl Command for SubCommand {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Split a string into rows by a regular expression",
+                example: "echo 'a   b c' | split row -r '\\s+'",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("a"),
+                        Value::test_string("b"),
+                        Value::test_string("c"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Split a string into at most two rows, keeping the remainder intact",
+                example: "echo 'a--b--c' | split row '--' -n 2",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("a"), Value::test_string("b--c")],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Split a string, keeping empty segments (default)",
+                example: "echo 'a,,b' | split row ','",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("a"),
+                        Value::test_string(""),
+                        Value::test_string("b"),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Split a string, dropping empty segments",
+                example: "echo 'a,,b' | split row ',' --drop-empty",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("a"), Value::test_string("b")],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description:
+                    "Split a string into at most two rows of char, keeping the remainder intact",
+                example: "echo 'abc' | split row '' -n 2",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("a"), Value::test_string("bc")],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 }
@@ -75,24 +141,82 @@ fn split_row(
 ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
     let name_span = call.head;
     let separator: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let number: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "number")?;
+    let limit = match number {
+        Some(n) if n.item > 0 => Some(n.item as usize),
+        Some(n) => {
+            return Err(ShellError::UnsupportedInput(
+                "--number must be a positive integer".into(),
+                n.span,
+            ))
+        }
+        None => None,
+    };
+    let regex = if call.has_flag("regex") {
+        Some(Regex::new(&separator.item).map_err(|err| {
+            ShellError::TypeMismatch(
+                format!("invalid regex '{}': {}", separator.item, err),
+                separator.span,
+            )
+        })?)
+    } else {
+        None
+    };
+    let drop_empty = call.has_flag("drop-empty");
 
     input.flat_map(
-        move |x| split_row_helper(&x, &separator, name_span),
+        move |x| split_row_helper(&x, &separator, regex.as_ref(), limit, drop_empty, name_span),
         engine_state.ctrlc.clone(),
     )
 }
 
-fn split_row_helper(v: &Value, separator: &Spanned<String>, name: Span) -> Vec<Value> {
+fn split_row_helper(
+    v: &Value,
+    separator: &Spanned<String>,
+    regex: Option<&Regex>,
+    limit: Option<usize>,
+    drop_empty: bool,
+    name: Span,
+) -> Vec<Value> {
     match v.span() {
         Ok(v_span) => {
             if let Ok(s) = v.as_string() {
-                let splitter = separator.item.replace("\\n", "\n");
-                s.split(&splitter)
+                let literal_splitter = separator.item.replace("\\n", "\n");
+                let mut parts: Vec<&str> = match (regex, limit) {
+                    (Some(re), Some(n)) => re.splitn(&s, n).collect(),
+                    (Some(re), None) => re.split(&s).collect(),
+                    // An empty literal separator matches at every char boundary
+                    // (including before the first and after the last char), so
+                    // `splitn(n, ..)` eats one of those real n pieces on a leading
+                    // empty match. Ask for one more piece and let the boundary trim
+                    // below drop that extra leading (and, if present, trailing) match.
+                    (None, Some(n)) if literal_splitter.is_empty() => s
+                        .splitn(n.saturating_add(1), literal_splitter.as_str())
+                        .collect(),
+                    (None, Some(n)) => s.splitn(n, &literal_splitter).collect(),
+                    (None, None) => s.split(&literal_splitter).collect(),
+                };
+
+                // An empty literal separator splits into individual chars, but
+                // `str::split("")` also yields a leading and trailing empty match that
+                // was never part of that result; drop those regardless of
+                // `--drop-empty` so plain char-splitting doesn't need it.
+                if regex.is_none() && literal_splitter.is_empty() {
+                    if parts.first() == Some(&"") {
+                        parts.remove(0);
+                    }
+                    if parts.last() == Some(&"") {
+                        parts.pop();
+                    }
+                }
+
+                parts
+                    .into_iter()
                     .filter_map(|s| {
-                        if s.trim() != "" {
-                            Some(Value::string(s, v_span))
-                        } else {
+                        if drop_empty && s.trim().is_empty() {
                             None
+                        } else {
+                            Some(Value::string(s, v_span))
                         }
                     })
                     .collect()